@@ -21,10 +21,15 @@
 //! * `time`: Time
 //! * `frequency`: Frequency
 //! * `length`: Length
+//! * `area`: Area
+//! * `volume`: Volume
 //! * `velocity`: Velocity, Acceleration
 //! * `digital`: LSB (least significant bits)
+//! * `temperature`: Temperature (kelvin, celsius, fahrenheit)
 //!
-//! Define custom units and conversions using the `impl_unit!`, `convert_div!` and `convert_unit!` macros.
+//! Define custom units and conversions using the `impl_unit!`, `convert_div!`, `convert_mul!` and
+//! `convert_unit!` macros. Units with an offset, like temperature, use `impl_affine_unit!` and
+//! `convert_affine!` instead.
 //!
 //! ```rust
 //! #[macro_use] extern crate yaum;
@@ -64,6 +69,13 @@
 //! =========
 //!
 //! By default, units are implemented on top of `f32`. Enable the `double_precision` feature for `f64`.
+//!
+//! Testing
+//! =======
+//!
+//! Enable the `quickcheck` or `proptest` feature to derive `Arbitrary` for generated unit
+//! types and to pull in [`testing`], a small set of round-trip invariants to check
+//! conversion factors and `convert_unit!` pairs against.
 
 #![cfg_attr(not(test), no_std)]
 #![allow(non_upper_case_globals)]
@@ -77,9 +89,61 @@ pub type Base = f64;
 /// Base type. `f64` if `double_precision` is enabled, otherwise `f32`.
 pub type Base = f32;
 
+/// Error returned by the [`core::str::FromStr`] implementations generated by [`impl_unit!`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParseUnitError {
+    /// The input was empty after trimming surrounding whitespace.
+    Empty,
+    /// The numeric part of the input could not be parsed.
+    InvalidNumber,
+    /// The trailing symbol did not match any unit known to the type.
+    UnknownSymbol,
+}
+
+/// A quantity paired with the best-fitting unit symbol for display purposes.
+///
+/// Produced by the `humanize` method generated by [`impl_unit!`], which picks the
+/// largest unit in which the magnitude of the value is at least `1.0` (falling back to
+/// the smallest known unit for tiny values), e.g. `(1_500.0 * m).humanize()`
+/// displays as `1.5 km`.
+pub struct Humanized<T> {
+    value: Base,
+    symbol: &'static str,
+    _unit: core::marker::PhantomData<T>,
+}
+
+impl<T> Humanized<T> {
+    // Must be fully `pub`, not `pub(crate)`: `impl_unit!` is a `#[macro_export]`ed macro,
+    // and macro hygiene ties a `pub(crate)` item's visibility to the crate that defines
+    // it, not the crate that invokes the macro, so downstream crates calling `humanize()`
+    // need to be able to construct this too.
+    pub const fn new(value: Base, symbol: &'static str) -> Self {
+        Self {
+            value,
+            symbol,
+            _unit: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> core::fmt::Display for Humanized<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} {}", precision, self.value, self.symbol),
+            None => write!(f, "{} {}", self.value, self.symbol),
+        }
+    }
+}
+
 #[macro_export]
 /// Define a unit. Specify units, constants in brackets.
 ///
+/// The generated type is generic over its storage (`$type<T = Base>`), so it can be
+/// backed by an integer or a fixed-point newtype instead of a float; only the core
+/// arithmetic (`Add`, `Sub`, `Mul`/`Div` by the storage type) is bounded on `T`. Unit
+/// readers, the `pub const` unit values and `FromStr`/`humanize` stay on the `Base`
+/// instantiation, so existing code like `1.0 * km` keeps compiling unchanged.
+///
 /// # Example:
 ///
 /// ```rust
@@ -94,75 +158,185 @@ pub type Base = f32;
 /// # fn main() {}
 /// ```
 macro_rules! impl_unit {
-    ($type:ident) => { crate::impl_unit!($type, {}); };
-    ($type:ident, { $( $unit:ident: $value:expr ),* }) => { crate::impl_unit!($type, crate::Base, { $( $unit: $value ),* }); };
+    ($type:ident) => { $crate::impl_unit!($type, {}); };
+    ($type:ident, { $( $unit:ident: $value:expr ),* }) => { $crate::impl_unit!($type, $crate::Base, { $( $unit: $value ),* }); };
 
     ($type:ident, $basetype:ty, {$( $unit:ident: $value:expr ),*}) => {
+        /// Generic over the storage type `T` (defaulting to [`Base`](crate::Base)) so a
+        /// quantity can ride on integers or a fixed-point newtype instead of floats.
         #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
-        pub struct $type($basetype);
+        pub struct $type<T = $basetype>(T);
 
-        impl $type {
-            pub const fn new(value: $basetype) -> Self {
+        impl<T> $type<T> {
+            pub const fn new(value: T) -> Self {
                 Self(value)
             }
+        }
 
-            pub const fn dimensionless(self) -> $basetype {
+        impl<T: Copy> $type<T> {
+            pub const fn dimensionless(self) -> T {
                 self.0
             }
-
-            $( pub fn $unit(self) -> $basetype {
-                self.dimensionless() * $value
-            } )*
         }
 
-        impl core::ops::Mul<$basetype> for $type {
-            type Output = $type;
+        impl<T: core::ops::Mul<Output = T>> core::ops::Mul<T> for $type<T> {
+            type Output = $type<T>;
 
-            fn mul(self, rhs: $basetype) -> Self::Output {
+            fn mul(self, rhs: T) -> Self::Output {
                 $type(self.0 * rhs)
             }
         }
 
-        impl core::ops::Mul<$type> for $basetype {
-            type Output = $type;
+        // Kept non-generic (scalar on the left): `T * $type<T>` would need `impl<T> Mul<$type<T>>
+        // for T`, which the orphan rules reject since `T` isn't a local type.
+        impl core::ops::Mul<$type<$basetype>> for $basetype {
+            type Output = $type<$basetype>;
 
-            fn mul(self, rhs: $type) -> Self::Output {
+            fn mul(self, rhs: $type<$basetype>) -> Self::Output {
                 $type(self * rhs.0)
             }
         }
 
-        impl core::ops::Add<$type> for $type {
-            type Output = $type;
+        impl<T: core::ops::Add<Output = T>> core::ops::Add<$type<T>> for $type<T> {
+            type Output = $type<T>;
 
-            fn add(self, rhs: $type) -> Self::Output {
+            fn add(self, rhs: $type<T>) -> Self::Output {
                 $type(self.0 + rhs.0)
             }
         }
 
-        impl core::ops::Sub<$type> for $type {
-            type Output = $type;
+        impl<T: core::ops::Sub<Output = T>> core::ops::Sub<$type<T>> for $type<T> {
+            type Output = $type<T>;
 
-            fn sub(self, rhs: $type) -> Self::Output {
+            fn sub(self, rhs: $type<T>) -> Self::Output {
                 $type(self.0 - rhs.0)
             }
         }
 
-        impl core::ops::Div<$type> for $type {
-            type Output = $basetype;
+        impl<T: core::ops::Div<Output = T>> core::ops::Div<$type<T>> for $type<T> {
+            type Output = T;
 
-            fn div(self, rhs: $type) -> Self::Output {
+            fn div(self, rhs: $type<T>) -> Self::Output {
                 self.0 / rhs.0
             }
         }
 
-        impl core::ops::Div<$basetype> for $type {
-            type Output = $type;
+        impl<T: core::ops::Div<Output = T>> core::ops::Div<T> for $type<T> {
+            type Output = $type<T>;
 
-            fn div(self, rhs: $basetype) -> Self::Output {
+            fn div(self, rhs: T) -> Self::Output {
                 $type(self.0 / rhs)
             }
         }
 
+        // Unit readers and the symbol table stay on the `$basetype` instantiation for
+        // back-compat; they read/write human-facing numbers, not arbitrary storage types.
+        impl $type<$basetype> {
+            $( pub fn $unit(self) -> $basetype {
+                self.dimensionless() / $value
+            } )*
+
+            /// The unit symbols known to this type, paired with their conversion factor.
+            ///
+            /// Shared by the [`FromStr`](core::str::FromStr) implementation (parsing) and
+            /// `humanize` (formatting) so both stay in sync with the unit list above.
+            pub const SYMBOLS: &'static [(&'static str, $basetype)] = &[
+                $( (stringify!($unit), $value) ),*
+            ];
+
+            /// Formats this quantity using whichever known unit reads most naturally,
+            /// e.g. `(1_500.0 * m).humanize()` displays as `1.5 km`.
+            ///
+            /// Note this picks the largest-factor unit satisfying the threshold out of
+            /// *all* of `SYMBOLS`, so a type whose unit list mixes unit systems (like
+            /// `Length`, which lists both metric and imperial units) may auto-select a
+            /// unit from either system.
+            pub fn humanize(self) -> $crate::Humanized<$type> {
+                let dimensionless = self.dimensionless();
+
+                let mut symbols = Self::SYMBOLS.iter();
+                let mut best = match symbols.next() {
+                    Some(entry) => *entry,
+                    // `impl_unit!($type)` with no units at all; nothing to select.
+                    None => return $crate::Humanized::new(dimensionless, ""),
+                };
+                for entry in Self::SYMBOLS {
+                    if entry.1 < best.1 {
+                        best = *entry;
+                    }
+                }
+                for entry in Self::SYMBOLS {
+                    if dimensionless.abs() / entry.1 >= 1.0 && entry.1 > best.1 {
+                        best = *entry;
+                    }
+                }
+
+                $crate::Humanized::new(dimensionless / best.1, best.0)
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        impl quickcheck::Arbitrary for $type<$basetype> {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Self::new(<$basetype>::arbitrary(g))
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl proptest::arbitrary::Arbitrary for $type<$basetype> {
+            type Parameters = ();
+            type Strategy = proptest::strategy::Map<
+                core::ops::RangeInclusive<$basetype>,
+                fn($basetype) -> Self,
+            >;
+
+            fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                use proptest::strategy::Strategy;
+
+                (<$basetype>::MIN..=<$basetype>::MAX).prop_map(Self::new)
+            }
+        }
+
+        impl core::str::FromStr for $type<$basetype> {
+            type Err = $crate::ParseUnitError;
+
+            // Named `input`, not `s`: a unit literally called `s` (as in the `time`
+            // module) generates a `pub const s: Time = ...`, and the parameter pattern
+            // would then be parsed as a match against that constant instead of a
+            // fresh binding.
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let input = input.trim();
+                if input.is_empty() {
+                    return Err($crate::ParseUnitError::Empty);
+                }
+
+                // Longest leading prefix of `input` that parses as a `$basetype`.
+                let mut split = 0;
+                for i in 1..=input.len() {
+                    if input.is_char_boundary(i) && input[..i].parse::<$basetype>().is_ok() {
+                        split = i;
+                    }
+                }
+                if split == 0 {
+                    return Err($crate::ParseUnitError::InvalidNumber);
+                }
+
+                let (number, symbol) = input.split_at(split);
+                let value: $basetype = number
+                    .parse()
+                    .map_err(|_| $crate::ParseUnitError::InvalidNumber)?;
+                let symbol = symbol.trim();
+
+                for (name, factor) in Self::SYMBOLS {
+                    if *name == symbol {
+                        return Ok(Self::new(value * factor));
+                    }
+                }
+
+                Err($crate::ParseUnitError::UnknownSymbol)
+            }
+        }
+
         $( pub const $unit: $type = $type($value); )*
     };
 }
@@ -206,6 +380,40 @@ macro_rules! convert_div {
     };
 }
 
+#[macro_export]
+/// Specify the result of multiplying two types, e.g. `Length * Length = Area`.
+///
+/// This only emits `impl Mul<$right> for $left`; when `$left` and `$right` differ,
+/// call it again with the arguments swapped to also get the commutative direction.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use] extern crate yaum;
+/// use yaum::*;
+///
+/// yaum::impl_unit!(Width, { m: 1.0 });
+/// yaum::impl_unit!(WidthArea, { m2: 1.0 });
+///
+/// // define relationship between units (WidthArea = Width * Width)
+/// yaum::convert_mul!(Width, Width, WidthArea);
+///
+/// # fn main() {
+/// assert_eq!(1.0 * m2, 1.0 * m * (1.0 * m));
+/// # }
+/// ```
+macro_rules! convert_mul {
+    ($left:ty, $right:ty, $result:ty) => {
+        impl core::ops::Mul<$right> for $left {
+            type Output = $result;
+
+            fn mul(self, rhs: $right) -> Self::Output {
+                <$result>::new(self.dimensionless() * rhs.dimensionless())
+            }
+        }
+    };
+}
+
 #[macro_export]
 /// Specify the conversion factor between two types.
 ///
@@ -248,6 +456,94 @@ macro_rules! convert_unit {
     };
 }
 
+#[macro_export]
+/// Define a unit whose sub-units differ from the base representation by both a
+/// scale and an offset (e.g. temperatures), rather than a pure factor.
+///
+/// Values are stored in the base unit (e.g. kelvin). Because scaling or adding
+/// two such quantities is not generally meaningful, the generated type does not
+/// get the `Base * Type` / `Type * Base` operators that [`impl_unit!`] produces;
+/// use [`convert_affine!`] to add named constructors and readers instead.
+///
+/// # Example:
+///
+/// ```rust
+/// #[macro_use] extern crate yaum;
+/// use yaum::*;
+///
+/// yaum::impl_affine_unit!(Temperature);
+/// yaum::convert_affine!(Temperature, celsius, from_celsius, 1.0, -273.15);
+/// # fn main() {}
+/// ```
+macro_rules! impl_affine_unit {
+    ($type:ident) => {
+        #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $type($crate::Base);
+
+        impl $type {
+            pub const fn from_base(base: $crate::Base) -> Self {
+                Self(base)
+            }
+
+            pub const fn dimensionless(self) -> $crate::Base {
+                self.0
+            }
+        }
+
+        impl core::ops::Sub<$type> for $type {
+            // The difference of two affine quantities is an interval in the base
+            // unit, not another point on the affine scale.
+            type Output = $crate::Base;
+
+            fn sub(self, rhs: $type) -> Self::Output {
+                self.dimensionless() - rhs.dimensionless()
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Add a named constructor and reader pair to a type declared with [`impl_affine_unit!`].
+///
+/// `unit_value = base * scale + offset`, so `$from` is the inverse: `base = (unit_value - offset) / scale`.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use] extern crate yaum;
+/// use yaum::*;
+///
+/// yaum::impl_affine_unit!(Temperature);
+/// yaum::convert_affine!(Temperature, celsius, from_celsius, 1.0, -273.15);
+///
+/// # fn main() {
+/// assert_eq!(25.0, Temperature::from_celsius(25.0).celsius());
+/// # }
+/// ```
+macro_rules! convert_affine {
+    ($type:ty, $unit:ident, $from:ident, $scale:expr, $offset:expr) => {
+        impl $type {
+            pub fn $from(value: $crate::Base) -> Self {
+                Self::from_base((value - $offset) / $scale)
+            }
+
+            pub fn $unit(self) -> $crate::Base {
+                self.dimensionless() * $scale + $offset
+            }
+        }
+    };
+}
+
+pub mod temperature {
+    impl_affine_unit!(Temperature);
+
+    // `unit_value = base * scale + offset`, so e.g. celsius = kelvin - 273.15 needs a
+    // *negative* offset here, not +273.15.
+    convert_affine!(Temperature, kelvin, from_kelvin, 1.0, 0.0);
+    convert_affine!(Temperature, celsius, from_celsius, 1.0, -273.15);
+    convert_affine!(Temperature, fahrenheit, from_fahrenheit, 9.0 / 5.0, -459.67);
+}
+
 pub mod frequency {
     impl_unit!(Frequency, {
         Hz: 1.0,
@@ -264,7 +560,13 @@ pub mod frequency {
 
     pub type SamplingFrequency = Frequency;
 
-    convert_unit!(Frequency, AngularFrequency, 2.0 * core::f32::consts::PI);
+    // `core::f64::consts::PI` cast to `Base` (not `core::f32::consts::PI`), so this
+    // keeps working under the `double_precision` feature instead of hardcoding `f32`.
+    convert_unit!(
+        Frequency,
+        AngularFrequency,
+        2.0 * core::f64::consts::PI as crate::Base
+    );
 }
 
 pub mod angle {
@@ -301,6 +603,26 @@ pub mod length {
     });
 }
 
+pub mod area {
+    impl_unit!(Area, {
+        cm2: 0.000_1,
+        m2: 1.0,
+        km2: 1_000_000.0,
+
+        inch2: 0.0254 * 0.0254,
+        ft2: 0.3048 * 0.3048
+    });
+}
+
+pub mod volume {
+    impl_unit!(Volume, {
+        ml: 0.000_001,
+        cm3: 0.000_001,
+        liter: 0.001,
+        m3: 1.0
+    });
+}
+
 pub mod velocity {
     impl_unit!(Velocity, {
         mps: 1.0,
@@ -320,6 +642,97 @@ pub mod velocity {
     }
 }
 
+/// Reusable property-based invariants for types generated by [`impl_unit!`].
+///
+/// Intended to be driven by `quickcheck`/`proptest` properties in downstream crates
+/// (and in this crate's own tests) to catch precision regressions in conversion factors.
+#[cfg(any(feature = "quickcheck", feature = "proptest"))]
+pub mod testing {
+    use crate::Base;
+
+    /// Default round-trip tolerance, *relative* to the magnitude of the value being
+    /// checked (absolute error grows with magnitude for floating point, so a fixed
+    /// absolute bound would reject large values). The magnitude is floored at `1.0`,
+    /// so values smaller than `1.0` are held to an absolute bound of `tolerance`.
+    /// Tighter for `double_precision` builds since `f64` carries more precision through
+    /// the multiply/divide.
+    #[cfg(feature = "double_precision")]
+    pub const DEFAULT_TOLERANCE: Base = 1e-9;
+    #[cfg(not(feature = "double_precision"))]
+    pub const DEFAULT_TOLERANCE: Base = 1e-4;
+
+    fn within_tolerance(value: Base, diff: Base, tolerance: Base) -> bool {
+        diff.abs() <= tolerance * value.abs().max(1.0)
+    }
+
+    /// Asserts that `value`, constructed through one [`impl_unit!`]-generated unit
+    /// (`construct_from`), read back through a *different* unit's reader
+    /// (`read_as_to`), reconstructed through that unit (`construct_to`), and finally
+    /// read back through the original unit's reader (`read_as_from`), round-trips
+    /// within `tolerance`.
+    ///
+    /// Takes the real generated constructors (e.g. `|v| v * mile`) and reader methods
+    /// (e.g. `|q: Length| q.km()`) rather than raw conversion factors, so it actually
+    /// exercises the macro-generated code instead of reimplementing its arithmetic.
+    pub fn assert_unit_roundtrip<T: Copy>(
+        value: Base,
+        construct_from: impl Fn(Base) -> T,
+        read_as_to: impl Fn(T) -> Base,
+        construct_to: impl Fn(Base) -> T,
+        read_as_from: impl Fn(T) -> Base,
+        tolerance: Base,
+    ) {
+        let as_to = read_as_to(construct_from(value));
+        let back = read_as_from(construct_to(as_to));
+
+        assert!(
+            within_tolerance(value, value - back, tolerance),
+            "round-trip {value} -> {as_to} -> {back} exceeded relative tolerance {tolerance}"
+        );
+    }
+
+    /// Asserts that `to_a(to_b(value))` round-trips back to `value` (via `dimensionless`)
+    /// within `tolerance`, the invariant every [`convert_unit!`] pair's `From` impls must
+    /// satisfy.
+    pub fn assert_convert_unit_roundtrip<A: Copy, B>(
+        value: A,
+        to_b: impl Fn(A) -> B,
+        to_a: impl Fn(B) -> A,
+        dimensionless: impl Fn(A) -> Base,
+        tolerance: Base,
+    ) {
+        let roundtripped = to_a(to_b(value));
+        let original = dimensionless(value);
+        let diff = original - dimensionless(roundtripped);
+        assert!(
+            within_tolerance(original, diff, tolerance),
+            "convert_unit! round-trip exceeded relative tolerance {tolerance}"
+        );
+    }
+
+    /// Asserts that `(a * b) / b` round-trips back to `a` (via `dimensionless`) within
+    /// `tolerance`, the invariant every [`convert_mul!`]/[`convert_div!`] pair must
+    /// satisfy.
+    pub fn assert_mul_div_roundtrip<A, B, R>(
+        a: A,
+        b: B,
+        dimensionless: impl Fn(A) -> Base,
+        tolerance: Base,
+    ) where
+        A: Copy + core::ops::Mul<B, Output = R>,
+        B: Copy,
+        R: core::ops::Div<B, Output = A>,
+    {
+        let back = (a * b) / b;
+        let original = dimensionless(a);
+        let diff = original - dimensionless(back);
+        assert!(
+            within_tolerance(original, diff, tolerance),
+            "convert_mul!/convert_div! round-trip exceeded relative tolerance {tolerance}"
+        );
+    }
+}
+
 pub mod conversions {
     use crate::*;
 
@@ -327,6 +740,14 @@ pub mod conversions {
     convert_div!(velocity::Velocity, time::Time, velocity::Acceleration);
     convert_div!(angle::Angle, time::Time, angle::AngularSpeed);
 
+    convert_mul!(length::Length, length::Length, area::Area);
+    convert_mul!(area::Area, length::Length, volume::Volume);
+    convert_mul!(length::Length, area::Area, volume::Volume);
+
+    convert_div!(area::Area, length::Length, length::Length);
+    convert_div!(volume::Volume, length::Length, area::Area);
+    convert_div!(volume::Volume, area::Area, length::Length);
+
     #[cfg(test)]
     mod tests {
         #[test]
@@ -358,11 +779,225 @@ pub mod conversions {
         #[test]
         fn convert_unit() {
             use crate::frequency::*;
-            use core::f32::consts::PI;
 
+            let pi = core::f64::consts::PI as crate::Base;
             let f = 50.0 * Hz;
 
-            assert_eq!(AngularFrequency::new(100.0 * PI), f.into());
+            assert_eq!(AngularFrequency::new(100.0 * pi), f.into());
+        }
+
+        #[test]
+        fn parse_unit() {
+            use crate::frequency::*;
+            use crate::length::*;
+            use crate::time::*;
+            use crate::ParseUnitError;
+            use core::str::FromStr;
+
+            assert_eq!(Ok(1.5 * km), Length::from_str("1.5 km"));
+            assert_eq!(Ok(1.0 * min), Time::from_str(" 60 s "));
+            assert_eq!(Ok(100.0 * kHz), Frequency::from_str("100 kHz"));
+
+            // Symbol matches must be exact, so "ms" must not be parsed as "m" + "s".
+            assert_eq!(Ok(5.0 * ms), Time::from_str("5ms"));
+
+            assert_eq!(Err(ParseUnitError::Empty), Length::from_str("   "));
+            assert_eq!(Err(ParseUnitError::InvalidNumber), Length::from_str("km"));
+            assert_eq!(Err(ParseUnitError::UnknownSymbol), Length::from_str("1.0 furlong"));
+        }
+
+        #[test]
+        fn humanize() {
+            use crate::length::*;
+            use crate::time::*;
+
+            assert_eq!("1.5 km", format!("{}", (1_500.0 * m).humanize()));
+            assert_eq!("1.5 min", format!("{}", (90.0 * s).humanize()));
+            assert_eq!("0.5 um", format!("{}", (0.000_000_5 * m).humanize()));
+        }
+
+        #[test]
+        fn humanize_negative() {
+            use crate::length::*;
+            use crate::time::*;
+
+            // The unit selection must compare on magnitude, not on the signed value,
+            // or negative quantities always fall back to the smallest unit.
+            assert_eq!("-1.5 km", format!("{}", (-1_500.0 * m).humanize()));
+            assert_eq!("-1.5 min", format!("{}", (-90.0 * s).humanize()));
+        }
+
+        #[test]
+        fn humanize_picks_across_unit_systems() {
+            use crate::length::*;
+
+            // `Length::SYMBOLS` lists both metric and imperial units in one table, so
+            // `humanize` is free to pick either system: here `inch` (0.0254) has
+            // a larger factor than `cm` (0.01) and still clears the `>= 1.0` threshold, so
+            // it wins over the metric unit a caller might expect.
+            //
+            // Pin the precision explicitly: unlike `f32`, `f64`'s default `Display` prints
+            // the full, not-round value here, so this would otherwise only pass under the
+            // default (non-`double_precision`) `Base`.
+            assert_eq!("1.968504 inch", format!("{:.6}", (0.05 * m).humanize()));
+        }
+
+        #[test]
+        fn affine_temperature() {
+            use crate::temperature::*;
+
+            let boiling = Temperature::from_celsius(100.0);
+            assert_eq!(373.15, boiling.kelvin());
+            assert!((212.0 - boiling.fahrenheit()).abs() < 0.01);
+
+            let delta = Temperature::from_celsius(100.0) - Temperature::from_celsius(0.0);
+            assert_eq!(100.0, delta);
+        }
+
+        #[test]
+        fn convert_multiplication() {
+            use crate::area::*;
+            use crate::length::*;
+            use crate::volume::*;
+
+            assert_eq!(1.0 * m2, 1.0 * m * (1.0 * m));
+            assert_eq!(1.0 * m2, (1.0 * m) * (1.0 * m));
+            assert_eq!(1.0 * m3, 1.0 * m2 * (1.0 * m));
+            assert_eq!(1.0 * m3, 1.0 * m * (1.0 * m2));
+
+            assert_eq!(1.0 * m, 1.0 * m2 / (1.0 * m));
+            assert_eq!(1.0 * m2, 1.0 * m3 / (1.0 * m));
+            assert_eq!(1.0 * m, 1.0 * m3 / (1.0 * m2));
+        }
+
+        #[test]
+        fn generic_storage() {
+            use crate::length::Length;
+
+            let a = Length::<i32>::new(2);
+            let b = Length::<i32>::new(6);
+
+            assert_eq!(Length::<i32>::new(8), a + b);
+            assert_eq!(Length::<i32>::new(4), b - a);
+            assert_eq!(Length::<i32>::new(6), a * 3);
+            assert_eq!(Length::<i32>::new(1), a / 2);
+            assert_eq!(3, b / a);
+        }
+
+        #[cfg(feature = "quickcheck")]
+        mod roundtrip {
+            use crate::angle::*;
+            use crate::area::*;
+            use crate::frequency::*;
+            use crate::length::*;
+            use crate::testing::*;
+            use crate::time::*;
+            use crate::velocity::*;
+
+            quickcheck::quickcheck! {
+                // Awkward factors like `mile` (1_609.34) are the ones most likely to
+                // accumulate rounding error, so exercise it explicitly.
+                fn length_mile_roundtrips(x: crate::Base) -> bool {
+                    if !x.is_finite() {
+                        return true;
+                    }
+                    let x = x % 10_000.0;
+                    assert_unit_roundtrip(
+                        x,
+                        |v| v * mile,
+                        |q: Length| q.km(),
+                        |v| v * km,
+                        |q: Length| q.mile(),
+                        DEFAULT_TOLERANCE,
+                    );
+                    true
+                }
+
+                fn angle_deg_rad_roundtrips(x: crate::Base) -> bool {
+                    if !x.is_finite() {
+                        return true;
+                    }
+                    let x = x % 360.0;
+                    assert_unit_roundtrip(
+                        x,
+                        |v| v * deg,
+                        |q: Angle| q.rad(),
+                        |v| v * rad,
+                        |q: Angle| q.deg(),
+                        DEFAULT_TOLERANCE,
+                    );
+                    true
+                }
+
+                fn time_min_h_roundtrips(x: crate::Base) -> bool {
+                    if !x.is_finite() {
+                        return true;
+                    }
+                    let x = x % 10_000.0;
+                    assert_unit_roundtrip(
+                        x,
+                        |v| v * min,
+                        |q: Time| q.h(),
+                        |v| v * h,
+                        |q: Time| q.min(),
+                        DEFAULT_TOLERANCE,
+                    );
+                    true
+                }
+
+                fn velocity_kph_mph_roundtrips(x: crate::Base) -> bool {
+                    if !x.is_finite() {
+                        return true;
+                    }
+                    let x = x % 10_000.0;
+                    assert_unit_roundtrip(
+                        x,
+                        |v| v * kph,
+                        |q: Velocity| q.mph(),
+                        |v| v * mph,
+                        |q: Velocity| q.kph(),
+                        DEFAULT_TOLERANCE,
+                    );
+                    true
+                }
+
+                fn convert_unit_frequency_roundtrips(x: crate::Base) -> bool {
+                    if !x.is_finite() {
+                        return true;
+                    }
+                    let f = Frequency::new(x % 1_000_000.0);
+                    assert_convert_unit_roundtrip(
+                        f,
+                        |f: Frequency| -> AngularFrequency { f.into() },
+                        |a: AngularFrequency| -> Frequency { a.into() },
+                        |f: Frequency| f.dimensionless(),
+                        DEFAULT_TOLERANCE,
+                    );
+                    true
+                }
+
+                // `convert_mul!(Length, Length, Area)` / `convert_div!(Area, Length, Length)`
+                fn area_length_roundtrips(x: crate::Base, y: crate::Base) -> bool {
+                    if !x.is_finite() || !y.is_finite() {
+                        return true;
+                    }
+                    let a = Length::new(x % 10_000.0);
+                    let b = Length::new((y % 10_000.0).abs() + 1.0);
+                    assert_mul_div_roundtrip(a, b, |l: Length| l.dimensionless(), DEFAULT_TOLERANCE);
+                    true
+                }
+
+                // `convert_mul!(Area, Length, Volume)` / `convert_div!(Volume, Length, Area)`
+                fn volume_area_length_roundtrips(x: crate::Base, y: crate::Base) -> bool {
+                    if !x.is_finite() || !y.is_finite() {
+                        return true;
+                    }
+                    let a = Area::new(x % 10_000.0);
+                    let b = Length::new((y % 10_000.0).abs() + 1.0);
+                    assert_mul_div_roundtrip(a, b, |a: Area| a.dimensionless(), DEFAULT_TOLERANCE);
+                    true
+                }
+            }
         }
     }
 }